@@ -2,6 +2,7 @@
 
 use super::completion::{CompletionModel, ANTHROPIC_VERSION_LATEST};
 use crate::client::{impl_conversion_traits, CompletionClient, ProviderClient};
+use crate::providers::rate_limit::RateLimiter;
 
 // ================================================================
 // Main Anthropic Client
@@ -14,6 +15,7 @@ pub struct ClientBuilder<'a> {
     base_url: &'a str,
     anthropic_version: &'a str,
     anthropic_betas: Option<Vec<&'a str>>,
+    max_requests_per_second: Option<u32>,
 }
 
 /// Create a new anthropic client using the builder
@@ -35,6 +37,7 @@ impl<'a> ClientBuilder<'a> {
             base_url: ANTHROPIC_API_BASE_URL,
             anthropic_version: ANTHROPIC_VERSION_LATEST,
             anthropic_betas: None,
+            max_requests_per_second: None,
         }
     }
 
@@ -58,12 +61,22 @@ impl<'a> ClientBuilder<'a> {
         self
     }
 
+    /// Throttle requests sent through [`Client::send`] to at most `max_requests_per_second`,
+    /// smoothing bursts across concurrent agent tasks instead of letting them all hit the API
+    /// at once. A `RequestBuilder` from [`Client::post`] or [`Client::post_with_betas`] that
+    /// is sent with `RequestBuilder::send` directly does not wait for the limiter.
+    pub fn max_requests_per_second(mut self, max_requests_per_second: u32) -> Self {
+        self.max_requests_per_second = Some(max_requests_per_second);
+        self
+    }
+
     pub fn build(self) -> Client {
         Client::new(
             self.api_key,
             self.base_url,
             self.anthropic_betas,
             self.anthropic_version,
+            self.max_requests_per_second,
         )
     }
 }
@@ -72,6 +85,10 @@ impl<'a> ClientBuilder<'a> {
 pub struct Client {
     base_url: String,
     http_client: reqwest::Client,
+    rate_limiter: Option<RateLimiter>,
+    /// Betas the client was built with; merged with any per-call betas in
+    /// [`Self::post_with_betas`] into a single `anthropic-beta` header value.
+    betas: Vec<String>,
 }
 
 impl Client {
@@ -82,7 +99,13 @@ impl Client {
     /// - If the API key or version cannot be parsed as a Json value from a String.
     ///   - This should really never happen.
     /// - If the reqwest client cannot be built (if the TLS backend cannot be initialized).
-    pub fn new(api_key: &str, base_url: &str, betas: Option<Vec<&str>>, version: &str) -> Self {
+    pub fn new(
+        api_key: &str,
+        base_url: &str,
+        betas: Option<Vec<&str>>,
+        version: &str,
+        max_requests_per_second: Option<u32>,
+    ) -> Self {
         Self {
             base_url: base_url.to_string(),
             http_client: reqwest::Client::builder()
@@ -93,25 +116,63 @@ impl Client {
                         "anthropic-version",
                         version.parse().expect("Anthropic version should parse"),
                     );
-                    if let Some(betas) = betas {
-                        headers.insert(
-                            "anthropic-beta",
-                            betas
-                                .join(",")
-                                .parse()
-                                .expect("Anthropic betas should parse"),
-                        );
-                    }
+                    // `anthropic-beta` is deliberately not set here: `post_with_betas`
+                    // always sets it explicitly from `self.betas`, so a per-call beta is
+                    // merged into one header value instead of appending a second one
+                    // alongside a default baked in at the reqwest client level (reqwest
+                    // merges client default headers and request headers additively, so the
+                    // two don't overwrite each other).
                     headers
                 })
                 .build()
                 .expect("Anthropic reqwest client should build"),
+            rate_limiter: max_requests_per_second.map(RateLimiter::new),
+            betas: betas
+                .unwrap_or_default()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
         }
     }
 
     pub fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        self.post_with_betas(path, &[])
+    }
+
+    /// Like [`Self::post`], but layers `betas` onto the `anthropic-beta` header of this
+    /// request only, on top of whatever the client was built with. This lets a single,
+    /// cloneable client mix calls that opt into a beta (e.g. `prompt-caching-2024-07-31`)
+    /// with calls that don't, instead of needing one `Client` per beta combination.
+    pub fn post_with_betas(&self, path: &str, betas: &[&str]) -> reqwest::RequestBuilder {
         let url = format!("{}/{}", self.base_url, path).replace("//", "/");
-        self.http_client.post(url)
+        let builder = self.http_client.post(url);
+
+        if self.betas.is_empty() && betas.is_empty() {
+            return builder;
+        }
+
+        let combined = self
+            .betas
+            .iter()
+            .map(String::as_str)
+            .chain(betas.iter().copied())
+            .collect::<Vec<_>>()
+            .join(",");
+        builder.header("anthropic-beta", combined)
+    }
+
+    /// Wait for a rate-limiter slot (if one was configured) and send `builder`. Provider
+    /// `CompletionModel` implementations should call this instead of `RequestBuilder::send`
+    /// directly so `max_requests_per_second` is actually enforced at the point a request goes
+    /// out, rather than only checked (and merely warned about) while building the request.
+    pub(crate) async fn send(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        builder.send().await
     }
 }
 
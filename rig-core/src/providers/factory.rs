@@ -0,0 +1,242 @@
+//! Declarative, config-driven provider/model factory.
+//!
+//! Lets an application describe which provider, model and credentials to use as a plain,
+//! serializable record (e.g. loaded from a settings file) instead of calling each
+//! provider's `ClientBuilder` directly, so a single versioned config schema can enumerate
+//! models across backends and swap providers at runtime. Provider-specific knobs travel in
+//! the config's free-form `parameters` JSON object and are merged verbatim into the
+//! completion request body via [`crate::json_utils::merge`], rather than being modeled as
+//! typed fields here — this keeps newly released model options usable without a crate
+//! update. For Vertex AI the merged body is the one that is sent. For Anthropic and Gemini
+//! the merge applies only to [`DynCompletionModel::completion_request_body`]; their
+//! [`DynCompletionModel::completion`] returns an error when `parameters` is non-empty.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::client::CompletionClient;
+use crate::completion::{self, CompletionError, CompletionRequest};
+use crate::json_utils::merge;
+use crate::providers::{anthropic, gemini, vertexai};
+
+/// Which backend a [`ProviderConfig`] targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Provider {
+    Anthropic,
+    Gemini,
+    VertexAi,
+}
+
+/// A flat, serializable description of a provider/model pairing, suitable for storing in
+/// an application's own settings schema.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderConfig {
+    pub provider: Provider,
+    pub model: String,
+    /// API key (Anthropic, Gemini) or bearer token (Vertex AI).
+    pub credentials: String,
+    /// `project_id` for Vertex AI; unused by the other providers.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// `location` for Vertex AI; unused by the other providers.
+    #[serde(default)]
+    pub location: Option<String>,
+    /// Endpoint override; maps to the Anthropic and Gemini providers' `base_url`. Ignored
+    /// for Vertex AI.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Provider-specific parameters (e.g. `{"anthropic_version": "..."}`), merged verbatim
+    /// into the completion request body for this model. Only Vertex AI sends the merged
+    /// body; for Anthropic and Gemini, `completion` returns an error when this is non-empty.
+    #[serde(default)]
+    pub parameters: Value,
+}
+
+/// A type-erased completion model that merges a [`ProviderConfig`]'s `parameters` into
+/// every request body, so callers can hold a `Box<dyn DynCompletionModel>` spanning
+/// multiple providers behind one config schema.
+pub trait DynCompletionModel: Send + Sync {
+    /// Build the outgoing JSON request body for `request`, with `parameters` merged in.
+    fn completion_request_body(&self, request: CompletionRequest) -> Result<Value, CompletionError>;
+
+    /// Send `request` to the underlying provider and return its response, with
+    /// `parameters` merged into the body that's actually sent wherever the wrapped
+    /// provider supports it (see [`CreatesCompletionRequest::completion_with_body`]).
+    fn completion<'a>(
+        &'a self,
+        request: CompletionRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<completion::CompletionResponse<Value>, CompletionError>> + Send + 'a>>;
+}
+
+struct MergingModel<M> {
+    inner: M,
+    parameters: Value,
+}
+
+/// `parameters` defaults to `{}` and is a no-op when empty, so the common case (no
+/// provider-specific overrides configured) can keep using the provider's own `completion`/
+/// `stream` unmodified instead of forcing every call through `completion_with_body`.
+fn is_empty_parameters(parameters: &Value) -> bool {
+    matches!(parameters, Value::Null) || parameters.as_object().is_some_and(|map| map.is_empty())
+}
+
+impl<M> DynCompletionModel for MergingModel<M>
+where
+    M: CreatesCompletionRequest + completion::CompletionModel<Response = Value> + Send + Sync,
+{
+    fn completion_request_body(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Value, CompletionError> {
+        let body = self.inner.completion_request_body(request)?;
+        Ok(merge(body, self.parameters.clone()))
+    }
+
+    fn completion<'a>(
+        &'a self,
+        request: CompletionRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<completion::CompletionResponse<Value>, CompletionError>> + Send + 'a>>
+    {
+        if is_empty_parameters(&self.parameters) {
+            return Box::pin(self.inner.completion(request));
+        }
+
+        match self.completion_request_body(request) {
+            Ok(body) => self.inner.completion_with_body(body),
+            Err(error) => Box::pin(async move { Err(error) }),
+        }
+    }
+}
+
+/// Implemented by each provider's `CompletionModel`, which already knows how to turn a
+/// [`CompletionRequest`] into that provider's wire-format JSON body.
+///
+/// Named distinctly from the inherent `create_completion_request`/`create_request_body`
+/// methods each provider's `CompletionModel` exposes, so implementations below call through
+/// to a clearly different method rather than relying on inherent-method shadowing (which
+/// would silently recurse if a provider ever renamed or removed its own method).
+pub(crate) trait CreatesCompletionRequest {
+    fn completion_request_body(&self, request: CompletionRequest) -> Result<Value, CompletionError>;
+
+    /// Send an already-built (and already `parameters`-merged) request body and return the
+    /// parsed response, bypassing the provider's own request-building.
+    ///
+    /// [`MergingModel::completion`] relies on this to actually apply `parameters` to the
+    /// request it sends — `completion_request_body` alone only produces a `Value` the
+    /// caller has no generic way to POST. The default implementation errors out rather than
+    /// silently ignoring `parameters`, since most providers' `CompletionModel::completion`
+    /// builds and sends its own request internally with no hook to substitute a different
+    /// body; override this for providers whose `CompletionModel` separates body-building
+    /// from sending (e.g. Vertex AI, which already does so to share Gemini/Anthropic body
+    /// builders across its own `completion`/`stream`).
+    fn completion_with_body<'a>(
+        &'a self,
+        body: Value,
+    ) -> Pin<Box<dyn Future<Output = Result<completion::CompletionResponse<Value>, CompletionError>> + Send + 'a>>
+    {
+        let _ = body;
+        Box::pin(async {
+            Err(CompletionError::ProviderError(
+                "this provider's CompletionModel has no hook to send a pre-built request \
+                 body, so configured `parameters` cannot be applied to a request it sends \
+                 itself; build the body with completion_request_body and post it through \
+                 the provider's own client instead of calling completion()"
+                    .to_string(),
+            ))
+        })
+    }
+}
+
+impl CreatesCompletionRequest for anthropic::completion::CompletionModel {
+    fn completion_request_body(&self, request: CompletionRequest) -> Result<Value, CompletionError> {
+        self.create_completion_request(request)
+    }
+}
+
+impl CreatesCompletionRequest for gemini::completion::CompletionModel {
+    fn completion_request_body(&self, request: CompletionRequest) -> Result<Value, CompletionError> {
+        self.create_completion_request(request)
+    }
+}
+
+impl CreatesCompletionRequest for vertexai::CompletionModel {
+    fn completion_request_body(&self, request: CompletionRequest) -> Result<Value, CompletionError> {
+        self.create_request_body(request)
+    }
+
+    fn completion_with_body<'a>(
+        &'a self,
+        body: Value,
+    ) -> Pin<Box<dyn Future<Output = Result<completion::CompletionResponse<Value>, CompletionError>> + Send + 'a>>
+    {
+        Box::pin(self.send_request_body(body))
+    }
+}
+
+/// Why building a [`DynCompletionModel`] from a [`ProviderConfig`] can fail.
+#[derive(Debug, thiserror::Error)]
+pub enum ProviderConfigError {
+    #[error("{provider:?} provider config requires `{field}`")]
+    MissingField {
+        provider: Provider,
+        field: &'static str,
+    },
+}
+
+/// Build a boxed, provider-agnostic completion model from a declarative [`ProviderConfig`].
+pub fn completion_model(
+    config: &ProviderConfig,
+) -> Result<Box<dyn DynCompletionModel>, ProviderConfigError> {
+    let model: Box<dyn DynCompletionModel> = match config.provider {
+        Provider::Anthropic => {
+            let mut builder = anthropic::ClientBuilder::new(&config.credentials);
+            if let Some(base_url) = &config.base_url {
+                builder = builder.base_url(base_url);
+            }
+            let client = builder.build();
+            Box::new(MergingModel {
+                inner: client.completion_model(&config.model),
+                parameters: config.parameters.clone(),
+            })
+        }
+        Provider::Gemini => {
+            let client = match &config.base_url {
+                Some(base_url) => gemini::Client::from_url(&config.credentials, base_url),
+                None => gemini::Client::new(&config.credentials),
+            };
+            Box::new(MergingModel {
+                inner: client.completion_model(&config.model),
+                parameters: config.parameters.clone(),
+            })
+        }
+        Provider::VertexAi => {
+            let project_id = config
+                .project_id
+                .as_deref()
+                .ok_or(ProviderConfigError::MissingField {
+                    provider: Provider::VertexAi,
+                    field: "project_id",
+                })?;
+            let location =
+                config
+                    .location
+                    .as_deref()
+                    .ok_or(ProviderConfigError::MissingField {
+                        provider: Provider::VertexAi,
+                        field: "location",
+                    })?;
+            let client =
+                vertexai::ClientBuilder::new(project_id, location, &config.credentials).build();
+            Box::new(MergingModel {
+                inner: client.completion_model(&config.model),
+                parameters: config.parameters.clone(),
+            })
+        }
+    };
+
+    Ok(model)
+}
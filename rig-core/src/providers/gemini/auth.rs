@@ -0,0 +1,232 @@
+//! Authentication helpers for the Google Gemini API.
+//!
+//! Besides a plain `?key=` API key, Google lets callers authenticate with a bearer token
+//! obtained from a service account via Application Default Credentials (ADC). This module
+//! implements just enough of that flow (JWT assertion -> OAuth2 token exchange) to keep a
+//! fresh access token cached for the lifetime of a `Client`, which is what Vertex AI and
+//! most enterprise GCP deployments require.
+
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Condvar, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::runtime::Handle;
+use tokio::sync::Mutex;
+
+const GOOGLE_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const GOOGLE_OAUTH_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+/// Refresh the cached token this many seconds before it actually expires, so an in-flight
+/// request never races a token that expires mid-call.
+const REFRESH_SKEW_SECONDS: i64 = 60;
+/// How often the background refresh loop checks whether the cached token needs renewing.
+/// `AdcTokenProvider::token` is cheap to call when the cached token is still fresh, so this
+/// can be fairly tight without hammering the token endpoint.
+const REFRESH_POLL_INTERVAL_SECONDS: u64 = 30;
+/// How long [`AdcTokenCache::current`] will block waiting for the very first token fetch
+/// (kicked off by [`AdcTokenCache::new`]) before giving up. Sending a request unauthenticated
+/// is never acceptable, so a slow or broken ADC exchange surfaces as a panic here instead.
+const FIRST_TOKEN_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("failed to read application default credentials: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse application default credentials: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to sign JWT assertion: {0}")]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+    #[error("failed to exchange JWT assertion for an access token: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+/// A service-account key, in the shape Google writes to the file pointed at by
+/// `GOOGLE_APPLICATION_CREDENTIALS` or to the well-known gcloud ADC location.
+#[derive(Debug, Clone, Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iss: String,
+    scope: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Fetches and caches OAuth2 access tokens for a service account loaded from Application
+/// Default Credentials, refreshing automatically once the cached token is close to expiry.
+#[derive(Clone)]
+pub struct AdcTokenProvider {
+    key: ServiceAccountKey,
+    http_client: reqwest::Client,
+    cached: Arc<Mutex<Option<CachedToken>>>,
+}
+
+impl AdcTokenProvider {
+    /// Load a service account key from `GOOGLE_APPLICATION_CREDENTIALS`, falling back to
+    /// the well-known gcloud ADC file location.
+    pub fn from_env() -> Result<Self, AuthError> {
+        let path = std::env::var("GOOGLE_APPLICATION_CREDENTIALS").unwrap_or_else(|_| default_adc_path());
+        Self::from_file(&path)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, AuthError> {
+        let contents = std::fs::read_to_string(path)?;
+        let key: ServiceAccountKey = serde_json::from_str(&contents)?;
+        Ok(Self {
+            key,
+            http_client: reqwest::Client::new(),
+            cached: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Return a cached access token, refreshing it first if it is missing or within
+    /// [`REFRESH_SKEW_SECONDS`] of expiring.
+    pub async fn token(&self) -> Result<String, AuthError> {
+        let mut cached = self.cached.lock().await;
+        if let Some(token) = cached.as_ref() {
+            if token.expires_at - chrono::Duration::seconds(REFRESH_SKEW_SECONDS) > Utc::now() {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let token = self.fetch_token().await?;
+        let access_token = token.access_token.clone();
+        *cached = Some(token);
+        Ok(access_token)
+    }
+
+    async fn fetch_token(&self) -> Result<CachedToken, AuthError> {
+        let now = Utc::now();
+        let claims = Claims {
+            iss: self.key.client_email.clone(),
+            scope: GOOGLE_OAUTH_SCOPE.to_string(),
+            aud: self.key.token_uri.clone(),
+            iat: now.timestamp(),
+            exp: (now + chrono::Duration::hours(1)).timestamp(),
+        };
+
+        let assertion = encode(
+            &Header::new(Algorithm::RS256),
+            &claims,
+            &EncodingKey::from_rsa_pem(self.key.private_key.as_bytes())?,
+        )?;
+
+        let response: TokenResponse = self
+            .http_client
+            .post(GOOGLE_TOKEN_URI)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(CachedToken {
+            access_token: response.access_token,
+            expires_at: now + chrono::Duration::seconds(response.expires_in),
+        })
+    }
+}
+
+fn default_adc_path() -> String {
+    let home = std::env::var("HOME").unwrap_or_default();
+    format!("{home}/.config/gcloud/application_default_credentials.json")
+}
+
+/// Keeps an ADC-issued bearer token available for synchronous reads, refreshing it in the
+/// background ahead of expiry so `Client::post`/`post_sse` can stay synchronous instead of
+/// awaiting a token fetch on every call. [`Self::current`] blocks (briefly, and only before
+/// the first token has arrived) rather than ever handing back "no token" — sending a
+/// Vertex/Gemini request unauthenticated is never the right fallback.
+#[derive(Clone)]
+pub(crate) struct AdcTokenCache {
+    state: Arc<(StdMutex<Option<String>>, Condvar)>,
+}
+
+impl AdcTokenCache {
+    /// # Panics
+    /// Panics if called outside a Tokio runtime — the refresh loop needs somewhere to run.
+    pub(crate) fn new(provider: AdcTokenProvider) -> Self {
+        let handle = Handle::try_current()
+            .expect("AdcTokenCache::new must be called from within a Tokio runtime");
+
+        let state = Arc::new((StdMutex::new(None), Condvar::new()));
+
+        // Hold only a `Weak` ref in the refresh loop so it exits once every `Client`
+        // sharing this cache has been dropped, instead of leaking an immortal task.
+        let slot = Arc::downgrade(&state);
+        handle.spawn(async move {
+            loop {
+                let Some(state) = slot.upgrade() else {
+                    break;
+                };
+
+                match provider.token().await {
+                    Ok(token) => {
+                        let (lock, ready) = &*state;
+                        *lock.lock().expect("ADC token cache lock poisoned") = Some(token);
+                        ready.notify_all();
+                    }
+                    Err(error) => tracing::error!("failed to refresh ADC access token: {error}"),
+                }
+                drop(state);
+
+                tokio::time::sleep(std::time::Duration::from_secs(
+                    REFRESH_POLL_INTERVAL_SECONDS,
+                ))
+                .await;
+            }
+        });
+
+        Self { state }
+    }
+
+    /// Returns the most recently refreshed access token, blocking for up to
+    /// [`FIRST_TOKEN_TIMEOUT`] if the background refresh (started by [`Self::new`]) hasn't
+    /// produced one yet.
+    ///
+    /// # Panics
+    /// Panics if no token has arrived within [`FIRST_TOKEN_TIMEOUT`] — this should only
+    /// happen if ADC itself is misconfigured or unreachable, in which case failing loudly
+    /// here is preferable to silently sending an unauthenticated request.
+    pub(crate) fn current(&self) -> String {
+        let (lock, ready) = &*self.state;
+        let guard = lock.lock().expect("ADC token cache lock poisoned");
+        let (guard, timeout) = ready
+            .wait_timeout_while(guard, FIRST_TOKEN_TIMEOUT, |token| token.is_none())
+            .expect("ADC token cache lock poisoned");
+
+        match &*guard {
+            Some(token) => token.clone(),
+            None => {
+                drop(guard);
+                assert!(timeout.timed_out());
+                panic!(
+                    "no ADC access token available after {FIRST_TOKEN_TIMEOUT:?}; check that \
+                     Application Default Credentials are configured correctly"
+                )
+            }
+        }
+    }
+}
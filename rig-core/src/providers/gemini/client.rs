@@ -1,9 +1,13 @@
 use super::{
-    completion::CompletionModel, embedding::EmbeddingModel, transcription::TranscriptionModel,
+    auth::{AdcTokenCache, AdcTokenProvider},
+    completion::CompletionModel,
+    embedding::EmbeddingModel,
+    transcription::TranscriptionModel,
 };
 use crate::client::{
     impl_conversion_traits, CompletionClient, EmbeddingsClient, ProviderClient, TranscriptionClient,
 };
+use crate::providers::rate_limit::RateLimiter;
 use crate::{
     agent::AgentBuilder,
     embeddings::{self},
@@ -18,21 +22,117 @@ use serde::{Deserialize, Serialize};
 // ================================================================
 const GEMINI_API_BASE_URL: &str = "https://generativelanguage.googleapis.com";
 
-#[derive(Debug, Clone)]
+/// How a [`Client`] authenticates its requests: either a plain API key appended as a
+/// `?key=` query parameter, or a bearer token obtained from Application Default
+/// Credentials (required by Vertex AI and most enterprise GCP deployments). The ADC token
+/// is kept in a background-refreshed [`AdcTokenCache`] so building a request stays a
+/// synchronous operation.
+#[derive(Clone)]
+enum AuthMethod {
+    ApiKey(String),
+    Adc(AdcTokenCache),
+}
+
+impl std::fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthMethod::ApiKey(_) => write!(f, "ApiKey(..)"),
+            AuthMethod::Adc(_) => write!(f, "Adc(..)"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ClientBuilder<'a> {
+    base_url: &'a str,
+    auth: ClientBuilderAuth<'a>,
+    max_requests_per_second: Option<u32>,
+}
+
+#[derive(Clone, Debug)]
+enum ClientBuilderAuth<'a> {
+    ApiKey(&'a str),
+    Adc,
+}
+
+/// Create a new Gemini client using the builder
+///
+/// # Example
+/// ```
+/// use rig::providers::gemini::ClientBuilder;
+///
+/// // Initialize the Google Gemini client with an API key
+/// let gemini_client = ClientBuilder::new("your-google-gemini-api-key").build();
+///
+/// // Or authenticate with Application Default Credentials instead
+/// let gemini_client = ClientBuilder::with_adc().build();
+/// ```
+impl<'a> ClientBuilder<'a> {
+    pub fn new(api_key: &'a str) -> Self {
+        Self {
+            base_url: GEMINI_API_BASE_URL,
+            auth: ClientBuilderAuth::ApiKey(api_key),
+            max_requests_per_second: None,
+        }
+    }
+
+    /// Authenticate using Application Default Credentials instead of an API key.
+    pub fn with_adc() -> Self {
+        Self {
+            base_url: GEMINI_API_BASE_URL,
+            auth: ClientBuilderAuth::Adc,
+            max_requests_per_second: None,
+        }
+    }
+
+    pub fn base_url(mut self, base_url: &'a str) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Throttle requests sent through [`Client::send`] to at most `max_requests_per_second`,
+    /// smoothing bursts across concurrent agent tasks instead of letting them all hit the API
+    /// at once. A `RequestBuilder` from [`Client::post`] or [`Client::post_sse`] that is sent
+    /// with `RequestBuilder::send` directly does not wait for the limiter.
+    pub fn max_requests_per_second(mut self, max_requests_per_second: u32) -> Self {
+        self.max_requests_per_second = Some(max_requests_per_second);
+        self
+    }
+
+    pub fn build(self) -> Client {
+        let auth = match self.auth {
+            ClientBuilderAuth::ApiKey(api_key) => AuthMethod::ApiKey(api_key.to_string()),
+            ClientBuilderAuth::Adc => {
+                let provider = AdcTokenProvider::from_env()
+                    .expect("application default credentials should load");
+                AuthMethod::Adc(AdcTokenCache::new(provider))
+            }
+        };
+        Client::from_auth(auth, self.base_url, self.max_requests_per_second)
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Client {
     base_url: String,
-    api_key: String,
+    auth: AuthMethod,
     http_client: reqwest::Client,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Client {
     pub fn new(api_key: &str) -> Self {
         Self::from_url(api_key, GEMINI_API_BASE_URL)
     }
+
     pub fn from_url(api_key: &str, base_url: &str) -> Self {
+        Self::from_auth(AuthMethod::ApiKey(api_key.to_string()), base_url, None)
+    }
+
+    fn from_auth(auth: AuthMethod, base_url: &str, max_requests_per_second: Option<u32>) -> Self {
         Self {
             base_url: base_url.to_string(),
-            api_key: api_key.to_string(),
+            auth,
             http_client: reqwest::Client::builder()
                 .default_headers({
                     let mut headers = reqwest::header::HeaderMap::new();
@@ -44,22 +144,59 @@ impl Client {
                 })
                 .build()
                 .expect("Gemini reqwest client should build"),
+            rate_limiter: max_requests_per_second.map(RateLimiter::new),
         }
     }
 
-    pub fn post(&self, path: &str) -> reqwest::RequestBuilder {
-        let url = format!("{}/{}?key={}", self.base_url, path, self.api_key).replace("//", "/");
+    /// Returns the request builder for `path`, authenticated either via the `?key=` query
+    /// parameter or the most recently refreshed ADC bearer token, depending on how this
+    /// client was built. Synchronous, so existing callers don't need to change: the ADC
+    /// token is refreshed out-of-band by [`AdcTokenCache`], which blocks briefly the first
+    /// time a request is built before any token has been fetched rather than letting one go
+    /// out unauthenticated. Rate limiting, if configured, is enforced separately in
+    /// [`Self::send`] rather than here, since blocking for a slot needs an async call site.
+    fn authed_request(&self, path: &str, extra_query: &str) -> reqwest::RequestBuilder {
+        match &self.auth {
+            AuthMethod::ApiKey(api_key) => {
+                let url = format!("{}/{}{extra_query}key={}", self.base_url, path, api_key)
+                    .replace("//", "/");
+                tracing::debug!("POST {}/{}{extra_query}key={}", self.base_url, path, "****");
+                self.http_client.post(url)
+            }
+            AuthMethod::Adc(cache) => {
+                let url = format!(
+                    "{}/{}{}",
+                    self.base_url,
+                    path,
+                    extra_query.trim_end_matches('&')
+                )
+                .replace("//", "/");
+                tracing::debug!("POST {url}");
+                self.http_client.post(url).bearer_auth(cache.current())
+            }
+        }
+    }
 
-        tracing::debug!("POST {}/{}?key={}", self.base_url, path, "****");
-        self.http_client.post(url)
+    pub fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        self.authed_request(path, "?")
     }
 
     pub fn post_sse(&self, path: &str) -> reqwest::RequestBuilder {
-        let url =
-            format!("{}/{}?alt=sse&key={}", self.base_url, path, self.api_key).replace("//", "/");
+        self.authed_request(path, "?alt=sse&")
+    }
 
-        tracing::debug!("POST {}/{}?alt=sse&key={}", self.base_url, path, "****");
-        self.http_client.post(url)
+    /// Wait for a rate-limiter slot (if one was configured) and send `builder`. Provider
+    /// `CompletionModel` implementations should call this instead of `RequestBuilder::send`
+    /// directly so `max_requests_per_second` is actually enforced at the point a request goes
+    /// out, rather than only checked (and merely warned about) while building the request.
+    pub(crate) async fn send(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        builder.send().await
     }
 
     /// Create an agent builder with the given completion model.
@@ -0,0 +1,69 @@
+//! A small per-client request-rate limiter shared by provider clients that expose a
+//! `max_requests_per_second` builder option (see [`crate::providers::anthropic::ClientBuilder`]
+//! and [`crate::providers::gemini::ClientBuilder`]).
+//!
+//! Enforcement happens via [`RateLimiter::acquire`], an async wait for a token bucket slot.
+//! It's awaited from an async send path (each provider's `Client::send`, right before the
+//! request actually goes out) rather than from the synchronous `post`/`post_sse` builders —
+//! those only *build* a `RequestBuilder` and have no way to block a caller, so a limiter
+//! driven from there could only warn after the fact instead of actually throttling anything.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Handle;
+use tokio::sync::Semaphore;
+
+/// Throttles callers to a configured rate using a token bucket: a `Semaphore` starts with
+/// `max_requests_per_second` permits, each caller acquires one before issuing its request,
+/// and a background task refills one permit per tick. This smooths bursts instead of just
+/// capping how many requests can be in flight at once.
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    semaphore: Arc<Semaphore>,
+}
+
+impl RateLimiter {
+    /// # Panics
+    /// Panics if called outside a Tokio runtime — the refill task needs somewhere to run.
+    pub(crate) fn new(max_requests_per_second: u32) -> Self {
+        let handle = Handle::try_current()
+            .expect("RateLimiter::new must be called from within a Tokio runtime");
+
+        let max_permits = max_requests_per_second.max(1) as usize;
+        let semaphore = Arc::new(Semaphore::new(max_permits));
+
+        // Hold only a `Weak` ref in the refill task so it exits once the owning `Client`
+        // (and every clone of it) is dropped, instead of leaking an immortal task.
+        let refill = Arc::downgrade(&semaphore);
+        let interval = Duration::from_secs(1) / max_permits as u32;
+        handle.spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let Some(semaphore) = refill.upgrade() else {
+                    break;
+                };
+                if semaphore.available_permits() < max_permits {
+                    semaphore.add_permits(1);
+                }
+            }
+        });
+
+        Self { semaphore }
+    }
+
+    /// Wait for a token bucket slot to become available, actually throttling the caller to
+    /// `max_requests_per_second` instead of merely observing that the bucket is dry. Intended
+    /// to be awaited right before a request is sent, from an async send path.
+    pub(crate) async fn acquire(&self) {
+        // The permit is forgotten rather than returned when dropped: capacity is restored
+        // by the background refill task on its own schedule, which is what turns this into
+        // a token bucket instead of a plain concurrency cap.
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("rate limiter semaphore should never be closed");
+        permit.forget();
+    }
+}
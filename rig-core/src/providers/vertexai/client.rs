@@ -0,0 +1,208 @@
+//! Vertex AI client api implementation
+
+use super::completion::CompletionModel;
+use crate::client::{impl_conversion_traits, CompletionClient, ProviderClient};
+use crate::providers::gemini::auth::{AdcTokenCache, AdcTokenProvider};
+
+// ================================================================
+// Main Vertex AI Client
+// ================================================================
+
+/// How a [`Client`] authenticates its requests: either a fixed bearer token supplied by the
+/// caller, or one obtained from Application Default Credentials (ADC) and refreshed
+/// automatically in the background. Google-issued Vertex AI access tokens are only valid
+/// for about an hour, so [`ClientBuilder::new`]'s fixed token will start failing requests
+/// once it expires — prefer [`ClientBuilder::with_adc`] for anything long-running.
+#[derive(Clone)]
+enum AuthMethod {
+    Static(String),
+    Adc(AdcTokenCache),
+}
+
+impl std::fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthMethod::Static(_) => write!(f, "Static(..)"),
+            AuthMethod::Adc(_) => write!(f, "Adc(..)"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ClientBuilder<'a> {
+    project_id: &'a str,
+    location: &'a str,
+    auth: ClientBuilderAuth<'a>,
+}
+
+#[derive(Clone, Debug)]
+enum ClientBuilderAuth<'a> {
+    Static(&'a str),
+    Adc,
+}
+
+/// Create a new Vertex AI client using the builder
+///
+/// # Example
+/// ```
+/// use rig::providers::vertexai::{ClientBuilder, self};
+///
+/// // Initialize the Vertex AI client with a fixed bearer token
+/// let vertexai_client = ClientBuilder::new("my-gcp-project", "us-central1", "your-access-token")
+///    .build();
+///
+/// // Or authenticate with Application Default Credentials, refreshed automatically
+/// let vertexai_client = ClientBuilder::with_adc("my-gcp-project", "us-central1").build();
+/// ```
+impl<'a> ClientBuilder<'a> {
+    /// `access_token` is sent as-is on every request. Vertex AI bearer tokens expire after
+    /// about an hour, so the caller is responsible for rebuilding the client with a fresh
+    /// token before then — use [`Self::with_adc`] instead if that's not practical.
+    pub fn new(project_id: &'a str, location: &'a str, access_token: &'a str) -> Self {
+        Self {
+            project_id,
+            location,
+            auth: ClientBuilderAuth::Static(access_token),
+        }
+    }
+
+    /// Authenticate using Application Default Credentials instead of a fixed token, so the
+    /// bearer token is refreshed automatically in the background (see
+    /// [`AdcTokenCache`](crate::providers::gemini::auth::AdcTokenCache)) instead of expiring
+    /// after about an hour.
+    pub fn with_adc(project_id: &'a str, location: &'a str) -> Self {
+        Self {
+            project_id,
+            location,
+            auth: ClientBuilderAuth::Adc,
+        }
+    }
+
+    pub fn build(self) -> Client {
+        let auth = match self.auth {
+            ClientBuilderAuth::Static(access_token) => AuthMethod::Static(access_token.to_string()),
+            ClientBuilderAuth::Adc => {
+                let provider = AdcTokenProvider::from_env()
+                    .expect("application default credentials should load");
+                AuthMethod::Adc(AdcTokenCache::new(provider))
+            }
+        };
+        Client::from_auth(self.project_id, self.location, auth)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Client {
+    project_id: String,
+    location: String,
+    auth: AuthMethod,
+    http_client: reqwest::Client,
+}
+
+impl Client {
+    /// Create a new Vertex AI client with the given project id, location and a fixed bearer
+    /// token. Note, you probably want to use the `ClientBuilder` instead — in particular,
+    /// [`ClientBuilder::with_adc`] if the token shouldn't be left to expire after ~1 hour.
+    ///
+    /// Panics:
+    /// - If the reqwest client cannot be built (if the TLS backend cannot be initialized).
+    pub fn new(project_id: &str, location: &str, access_token: &str) -> Self {
+        Self::from_auth(
+            project_id,
+            location,
+            AuthMethod::Static(access_token.to_string()),
+        )
+    }
+
+    fn from_auth(project_id: &str, location: &str, auth: AuthMethod) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            location: location.to_string(),
+            auth,
+            http_client: reqwest::Client::builder()
+                .default_headers({
+                    let mut headers = reqwest::header::HeaderMap::new();
+                    headers.insert(
+                        reqwest::header::CONTENT_TYPE,
+                        "application/json".parse().unwrap(),
+                    );
+                    headers
+                })
+                .build()
+                .expect("Vertex AI reqwest client should build"),
+        }
+    }
+
+    /// Vertex AI serves Claude models (`claude-3-*@YYYYMMDD`) under the `anthropic`
+    /// publisher namespace and everything else under `google`.
+    fn publisher_for(model: &str) -> &'static str {
+        if model.starts_with("claude-") {
+            "anthropic"
+        } else {
+            "google"
+        }
+    }
+
+    fn endpoint(&self, model: &str, action: &str) -> String {
+        format!(
+            "https://{location}-aiplatform.googleapis.com/v1/projects/{project_id}/locations/{location}/publishers/{publisher}/models/{model}:{action}",
+            location = self.location,
+            project_id = self.project_id,
+            publisher = Self::publisher_for(model),
+            model = model,
+            action = action,
+        )
+    }
+
+    /// Returns the request builder for `model`/`action`, authenticated with either the
+    /// fixed bearer token or the most recently refreshed ADC token, depending on how this
+    /// client was built. For ADC, this blocks briefly if the background refresh hasn't
+    /// produced a token yet rather than ever sending the request unauthenticated (see
+    /// [`AdcTokenCache::current`](crate::providers::gemini::auth::AdcTokenCache::current)).
+    fn authed_request(&self, model: &str, action: &str) -> reqwest::RequestBuilder {
+        let builder = self.http_client.post(self.endpoint(model, action));
+        match &self.auth {
+            AuthMethod::Static(access_token) => builder.bearer_auth(access_token),
+            AuthMethod::Adc(cache) => builder.bearer_auth(cache.current()),
+        }
+    }
+
+    /// Build a request against the non-streaming `generateContent` action for `model`.
+    pub fn post(&self, model: &str) -> reqwest::RequestBuilder {
+        self.authed_request(model, "generateContent")
+    }
+
+    /// Build a request against the streaming `streamGenerateContent` action for `model`.
+    pub fn post_sse(&self, model: &str) -> reqwest::RequestBuilder {
+        self.authed_request(model, "streamGenerateContent")
+    }
+}
+
+impl ProviderClient for Client {
+    /// Create a new Vertex AI client from the `GOOGLE_CLOUD_PROJECT`, `GOOGLE_CLOUD_LOCATION`
+    /// and `GOOGLE_ACCESS_TOKEN` environment variables.
+    /// Panics if any of these environment variables is not set.
+    fn from_env() -> Self {
+        let project_id =
+            std::env::var("GOOGLE_CLOUD_PROJECT").expect("GOOGLE_CLOUD_PROJECT not set");
+        let location =
+            std::env::var("GOOGLE_CLOUD_LOCATION").expect("GOOGLE_CLOUD_LOCATION not set");
+        let access_token =
+            std::env::var("GOOGLE_ACCESS_TOKEN").expect("GOOGLE_ACCESS_TOKEN not set");
+        ClientBuilder::new(&project_id, &location, &access_token).build()
+    }
+}
+
+impl CompletionClient for Client {
+    type CompletionModel = CompletionModel;
+    fn completion_model(&self, model: &str) -> CompletionModel {
+        CompletionModel::new(self.clone(), model)
+    }
+}
+
+impl_conversion_traits!(
+    AsTranscription,
+    AsEmbeddings,
+    AsImageGeneration,
+    AsAudioGeneration for Client
+);
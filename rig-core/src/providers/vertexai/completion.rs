@@ -0,0 +1,217 @@
+//! Vertex AI completion model.
+//!
+//! Vertex AI exposes both Gemini and Claude models behind the same `:generateContent` /
+//! `:streamGenerateContent` endpoints, so this model dispatches on the model name and
+//! reuses the Gemini and Anthropic providers' own request/response types rather than
+//! defining a third wire format.
+
+use futures::stream::{self as futures_stream, StreamExt};
+use serde_json::{json, Value};
+use std::pin::Pin;
+
+use super::client::Client;
+use crate::completion::{self, CompletionError, CompletionRequest};
+use crate::json_utils::merge;
+use crate::providers::{anthropic, gemini};
+use crate::streaming::StreamingCompletionResponse;
+
+/// `claude-3-*@YYYYMMDD`-style models are served by Anthropic on Vertex; everything else
+/// is assumed to be a Gemini model.
+fn is_claude_model(model: &str) -> bool {
+    model.starts_with("claude-")
+}
+
+#[derive(Clone)]
+pub struct CompletionModel {
+    client: Client,
+    anthropic_model: anthropic::completion::CompletionModel,
+    gemini_model: gemini::completion::CompletionModel,
+    pub model: String,
+}
+
+impl CompletionModel {
+    pub fn new(client: Client, model: &str) -> Self {
+        Self {
+            // The inner models are only used to build request/response bodies; all
+            // requests are actually sent through `client`, so their own clients are unused.
+            anthropic_model: anthropic::completion::CompletionModel::new(
+                anthropic::ClientBuilder::new("").build(),
+                model,
+            ),
+            gemini_model: gemini::completion::CompletionModel::new(gemini::Client::new(""), model),
+            client,
+            model: model.to_string(),
+        }
+    }
+
+    pub(crate) fn create_request_body(
+        &self,
+        completion_request: CompletionRequest,
+    ) -> Result<serde_json::Value, CompletionError> {
+        if is_claude_model(&self.model) {
+            let mut request = self
+                .anthropic_model
+                .create_completion_request(completion_request)?;
+
+            // Vertex's `anthropic` publisher takes the model from the URL path (this
+            // model's `:generateContent`/`:streamGenerateContent` endpoint is already scoped
+            // to `self.model`) and rejects a body that also carries a `model` field the way
+            // the public Anthropic Messages API body does, so strip it if present.
+            if let Some(body) = request.as_object_mut() {
+                body.remove("model");
+            }
+
+            // Vertex AI expects `anthropic_version` inlined in the body rather than sent
+            // as the `anthropic-version` header the public Anthropic API uses.
+            Ok(merge(
+                request,
+                json!({ "anthropic_version": anthropic::ANTHROPIC_VERSION_LATEST }),
+            ))
+        } else {
+            self.gemini_model
+                .create_completion_request(completion_request)
+        }
+    }
+
+    /// Extract the text delta out of one raw SSE chunk, if it carries one.
+    ///
+    /// Gemini and Anthropic frame streamed text completely differently (a `candidates`
+    /// array of parts vs. a tagged `content_block_delta` event), so a chunk's wire shape
+    /// depends on `is_claude`. Normalizing both into the same `{"text": "..."}` shape means a
+    /// caller consuming this model's stream only has to understand one format, not two;
+    /// non-text control events (`message_start`, `ping`, usage deltas, tool-call deltas, ...)
+    /// are dropped rather than passed through as opaque, provider-specific JSON.
+    fn extract_text_delta(is_claude: bool, chunk: &Value) -> Option<Value> {
+        let text = if is_claude {
+            if chunk.get("type")?.as_str()? != "content_block_delta" {
+                return None;
+            }
+            chunk.get("delta")?.get("text")?.as_str()?
+        } else {
+            chunk
+                .get("candidates")?
+                .as_array()?
+                .first()?
+                .get("content")?
+                .get("parts")?
+                .as_array()?
+                .first()?
+                .get("text")?
+                .as_str()?
+        };
+
+        Some(json!({ "text": text }))
+    }
+
+    /// Stream a completion.
+    ///
+    /// Vertex's `streamGenerateContent` action returns each backend's *native* streaming
+    /// format (Gemini's SSE chunks, or Anthropic's event stream when proxying a Claude
+    /// model) rather than OpenAI's, so — unlike [`together`](crate::providers::together),
+    /// which is OpenAI-compatible — this can't reuse
+    /// [`openai::send_compatible_streaming_request`](crate::providers::openai::send_compatible_streaming_request).
+    /// Both formats frame every event as a `data: <json>` SSE line though, so a single
+    /// provider-agnostic decoder handles both instead of a second `is_claude_model` branch;
+    /// [`Self::extract_text_delta`] then normalizes each decoded chunk into a common
+    /// `{"text": "..."}` shape so callers don't have to understand both wire formats.
+    pub(crate) async fn stream(
+        &self,
+        completion_request: CompletionRequest,
+    ) -> Result<StreamingCompletionResponse<Value>, CompletionError> {
+        let request = self.create_request_body(completion_request)?;
+
+        let response = self
+            .client
+            .post_sse(&self.model)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| CompletionError::HttpError(e.into()))?;
+
+        if !response.status().is_success() {
+            return Err(CompletionError::ProviderError(
+                response
+                    .text()
+                    .await
+                    .map_err(|e| CompletionError::HttpError(e.into()))?,
+            ));
+        }
+
+        let is_claude = is_claude_model(&self.model);
+        let decoded: Pin<Box<dyn futures::Stream<Item = Result<Value, CompletionError>> + Send>> =
+            Box::pin(
+                response
+                    .bytes_stream()
+                    .map(move |chunk| -> Result<Vec<Value>, CompletionError> {
+                        let bytes = chunk.map_err(|e| CompletionError::HttpError(e.into()))?;
+                        Ok(String::from_utf8_lossy(&bytes)
+                            .lines()
+                            .filter_map(|line| line.strip_prefix("data:"))
+                            .map(str::trim)
+                            .filter(|data| !data.is_empty() && *data != "[DONE]")
+                            .filter_map(|data| serde_json::from_str::<Value>(data).ok())
+                            .filter_map(|chunk| Self::extract_text_delta(is_claude, &chunk))
+                            .collect())
+                    })
+                    .flat_map(|chunk| {
+                        futures_stream::iter(match chunk {
+                            Ok(values) => values.into_iter().map(Ok).collect::<Vec<_>>(),
+                            Err(error) => vec![Err(error)],
+                        })
+                    }),
+            );
+
+        Ok(StreamingCompletionResponse::new(decoded))
+    }
+
+    /// Post an already-built request `body` to the non-streaming `generateContent` action
+    /// and parse the response, without building a body from a [`CompletionRequest`] first.
+    ///
+    /// Factored out of the [`completion::CompletionModel::completion`] impl below so that
+    /// [`crate::providers::factory::MergingModel`] can send a `parameters`-merged body
+    /// through this model without duplicating the send/parse logic.
+    pub(crate) async fn send_request_body(
+        &self,
+        body: serde_json::Value,
+    ) -> Result<completion::CompletionResponse<Value>, CompletionError> {
+        let response = self
+            .client
+            .post(&self.model)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| CompletionError::HttpError(e.into()))?;
+
+        if !response.status().is_success() {
+            return Err(CompletionError::ProviderError(
+                response
+                    .text()
+                    .await
+                    .map_err(|e| CompletionError::HttpError(e.into()))?,
+            ));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| CompletionError::HttpError(e.into()))?;
+
+        if is_claude_model(&self.model) {
+            anthropic::completion::CompletionResponse::try_from(body).map(Into::into)
+        } else {
+            gemini::completion::CompletionResponse::try_from(body).map(Into::into)
+        }
+    }
+}
+
+impl completion::CompletionModel for CompletionModel {
+    type Response = serde_json::Value;
+
+    async fn completion(
+        &self,
+        completion_request: CompletionRequest,
+    ) -> Result<completion::CompletionResponse<Self::Response>, CompletionError> {
+        let request = self.create_request_body(completion_request)?;
+        self.send_request_body(request).await
+    }
+}
@@ -0,0 +1,20 @@
+//! Google Vertex AI provider, allowing both Gemini and Claude models to be served from a
+//! single GCP project behind the same `CompletionClient`/`AsStreaming` surface used by the
+//! other providers in this crate.
+//!
+//! # Example
+//! ```
+//! use rig::client::CompletionClient;
+//! use rig::providers::vertexai;
+//!
+//! let client = vertexai::ClientBuilder::new("my-gcp-project", "us-central1", "access-token")
+//!     .build();
+//!
+//! let gemini_model = client.completion_model("gemini-1.5-pro");
+//! let claude_model = client.completion_model("claude-3-5-sonnet@20240620");
+//! ```
+pub mod client;
+pub mod completion;
+
+pub use client::{Client, ClientBuilder};
+pub use completion::CompletionModel;